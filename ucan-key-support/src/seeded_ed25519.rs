@@ -0,0 +1,99 @@
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signature, Signer, Verifier};
+use hkdf::Hkdf;
+use sha2::Sha256;
+use ucan::crypto::KeyMaterial;
+
+pub const ED25519_ALGORITHM: &str = "EdDSA";
+
+// Caller-provided `info`, not this salt, is what distinguishes one derived key from another.
+const HKDF_SALT: &[u8] = b"rs-ucan/seeded-ed25519/v1";
+
+const ED25519_DID_MULTICODEC_PREFIX: [u8; 2] = [0xed, 0x01];
+
+// Deriving with the same (seed, info) pair always reproduces the same DID;
+// changing `info` yields an independent, unlinkable key.
+pub struct SeededEd25519KeyMaterial(Keypair);
+
+impl SeededEd25519KeyMaterial {
+    pub fn from_seed(seed: &[u8], info: &[u8]) -> Result<SeededEd25519KeyMaterial> {
+        let hkdf = Hkdf::<Sha256>::new(Some(HKDF_SALT), seed);
+        let mut secret_key_bytes = [0u8; ed25519_dalek::SECRET_KEY_LENGTH];
+
+        hkdf.expand(info, &mut secret_key_bytes)
+            .map_err(|error| anyhow!("Failed to derive Ed25519 secret key: {}", error))?;
+
+        let secret = SecretKey::from_bytes(&secret_key_bytes)
+            .map_err(|error| anyhow!("Failed to build Ed25519 secret key: {}", error))?;
+        let public = PublicKey::from(&secret);
+
+        Ok(SeededEd25519KeyMaterial(Keypair { secret, public }))
+    }
+}
+
+#[async_trait(?Send)]
+impl KeyMaterial for SeededEd25519KeyMaterial {
+    fn get_jwt_algorithm_name(&self) -> String {
+        ED25519_ALGORITHM.into()
+    }
+
+    async fn get_did(&self) -> Result<String> {
+        let mut tagged_public_key = Vec::with_capacity(
+            ED25519_DID_MULTICODEC_PREFIX.len() + ed25519_dalek::PUBLIC_KEY_LENGTH,
+        );
+        tagged_public_key.extend_from_slice(&ED25519_DID_MULTICODEC_PREFIX);
+        tagged_public_key.extend_from_slice(self.0.public.as_bytes());
+
+        Ok(format!(
+            "did:key:{}",
+            multibase::encode(multibase::Base::Base58Btc, tagged_public_key)
+        ))
+    }
+
+    async fn sign(&self, payload: &[u8]) -> Result<Vec<u8>> {
+        Ok(self.0.sign(payload).to_bytes().to_vec())
+    }
+
+    async fn verify(&self, payload: &[u8], signature: &[u8]) -> Result<()> {
+        let signature = Signature::try_from(signature)
+            .map_err(|error| anyhow!("Could not parse signature: {}", error))?;
+
+        self.0
+            .public
+            .verify(payload, &signature)
+            .map_err(|_| anyhow!("Could not verify signature"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SeededEd25519KeyMaterial;
+    use ucan::crypto::KeyMaterial;
+
+    #[async_std::test]
+    async fn it_can_sign_and_verify_data() {
+        let key_material =
+            SeededEd25519KeyMaterial::from_seed(b"super secret seed", b"test").unwrap();
+        let data = &[0xdeu8, 0xad, 0xbe, 0xef];
+        let signature = key_material.sign(data).await.unwrap();
+
+        key_material.verify(data, signature.as_ref()).await.unwrap();
+    }
+
+    #[async_std::test]
+    async fn it_derives_the_same_did_from_the_same_seed_and_info() {
+        let a = SeededEd25519KeyMaterial::from_seed(b"super secret seed", b"test").unwrap();
+        let b = SeededEd25519KeyMaterial::from_seed(b"super secret seed", b"test").unwrap();
+
+        assert_eq!(a.get_did().await.unwrap(), b.get_did().await.unwrap());
+    }
+
+    #[async_std::test]
+    async fn it_derives_an_unlinkable_did_from_a_different_info() {
+        let a = SeededEd25519KeyMaterial::from_seed(b"super secret seed", b"app one").unwrap();
+        let b = SeededEd25519KeyMaterial::from_seed(b"super secret seed", b"app two").unwrap();
+
+        assert_ne!(a.get_did().await.unwrap(), b.get_did().await.unwrap());
+    }
+}
@@ -8,7 +8,106 @@ use wasm_bindgen::{JsCast, JsValue};
 use wasm_bindgen_futures::JsFuture;
 use web_sys::{Crypto, CryptoKey, CryptoKeyPair, SubtleCrypto};
 
-pub struct WebCryptoRsaKeyMaterial(pub CryptoKey, pub Option<CryptoKey>);
+pub const ECDSA_ALGORITHM: &str = "ECDSA";
+pub const AES_WRAPPING_ALGORITHM: &str = "AES-GCM";
+
+// Recommended AES-GCM IV size (96 bits), per NIST SP 800-38D.
+const AES_GCM_IV_LENGTH: usize = 12;
+
+// The NIST curves that browsers expose via `SubtleCrypto`'s ECDSA algorithm.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EcdsaCurve {
+    P256,
+    P384,
+    P521,
+}
+
+impl EcdsaCurve {
+    fn named_curve(&self) -> &'static str {
+        match self {
+            EcdsaCurve::P256 => "P-256",
+            EcdsaCurve::P384 => "P-384",
+            EcdsaCurve::P521 => "P-521",
+        }
+    }
+
+    fn hash_name(&self) -> &'static str {
+        match self {
+            EcdsaCurve::P256 => "SHA-256",
+            EcdsaCurve::P384 => "SHA-384",
+            EcdsaCurve::P521 => "SHA-512",
+        }
+    }
+
+    fn jwt_algorithm_name(&self) -> &'static str {
+        match self {
+            EcdsaCurve::P256 => "ES256",
+            EcdsaCurve::P384 => "ES384",
+            EcdsaCurve::P521 => "ES512",
+        }
+    }
+
+    fn coordinate_size(&self) -> usize {
+        match self {
+            EcdsaCurve::P256 => 32,
+            EcdsaCurve::P384 => 48,
+            EcdsaCurve::P521 => 66,
+        }
+    }
+
+    // did:key multicodec prefix (varint-encoded) for a compressed public key
+    // on this curve.
+    fn multicodec_prefix(&self) -> &'static [u8] {
+        match self {
+            EcdsaCurve::P256 => &[0x80, 0x24],
+            EcdsaCurve::P384 => &[0x81, 0x24],
+            EcdsaCurve::P521 => &[0x82, 0x24],
+        }
+    }
+}
+
+// The RSASSA-PSS hash variants the PS256/PS384/PS512 algorithms are named
+// after. The PSS salt length is tied to the digest length, per RFC 7518 §3.5.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RsaHash {
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl RsaHash {
+    fn name(&self) -> &'static str {
+        match self {
+            RsaHash::Sha256 => "SHA-256",
+            RsaHash::Sha384 => "SHA-384",
+            RsaHash::Sha512 => "SHA-512",
+        }
+    }
+
+    fn salt_length(&self) -> u32 {
+        match self {
+            RsaHash::Sha256 => 32,
+            RsaHash::Sha384 => 48,
+            RsaHash::Sha512 => 64,
+        }
+    }
+
+    fn jwt_algorithm_name(&self) -> &'static str {
+        match self {
+            RsaHash::Sha256 => "PS256",
+            RsaHash::Sha384 => "PS384",
+            RsaHash::Sha512 => "PS512",
+        }
+    }
+}
+
+impl Default for RsaHash {
+    fn default() -> Self {
+        RsaHash::Sha256
+    }
+}
+
+pub struct WebCryptoRsaKeyMaterial(pub CryptoKey, pub Option<CryptoKey>, pub RsaHash);
 
 impl WebCryptoRsaKeyMaterial {
     fn get_subtle_crypto() -> Result<SubtleCrypto> {
@@ -30,6 +129,16 @@ impl WebCryptoRsaKeyMaterial {
     }
 
     pub async fn generate(key_size: Option<u32>) -> Result<WebCryptoRsaKeyMaterial> {
+        Self::generate_extractable(key_size, RsaHash::default(), false).await
+    }
+
+    // Like `generate`, but the CryptoKeys can be marked extractable and
+    // `hash` selects which of PS256/PS384/PS512 the key signs and verifies with.
+    pub async fn generate_extractable(
+        key_size: Option<u32>,
+        hash: RsaHash,
+        extractable: bool,
+    ) -> Result<WebCryptoRsaKeyMaterial> {
         let subtle_crypto = Self::get_subtle_crypto()?;
         let algorithm = Object::new();
 
@@ -57,13 +166,21 @@ impl WebCryptoRsaKeyMaterial {
         )
         .map_err(|error| anyhow!("{:?}", error))?;
 
-        let hash = Object::new();
+        let hash_object = Object::new();
 
-        Reflect::set(&hash, &JsValue::from("name"), &JsValue::from("SHA-256"))
-            .map_err(|error| anyhow!("{:?}", error))?;
+        Reflect::set(
+            &hash_object,
+            &JsValue::from("name"),
+            &JsValue::from(hash.name()),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
 
-        Reflect::set(&algorithm, &JsValue::from("hash"), &JsValue::from(hash))
-            .map_err(|error| anyhow!("{:?}", error))?;
+        Reflect::set(
+            &algorithm,
+            &JsValue::from("hash"),
+            &JsValue::from(hash_object),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
 
         let uses = Array::new();
 
@@ -71,7 +188,7 @@ impl WebCryptoRsaKeyMaterial {
         uses.push(&JsValue::from("verify"));
 
         let crypto_key_pair_generates = subtle_crypto
-            .generate_key_with_object(&algorithm, false, &uses)
+            .generate_key_with_object(&algorithm, extractable, &uses)
             .map_err(|error| anyhow!("{:?}", error))?;
         let crypto_key_pair = CryptoKeyPair::from(
             JsFuture::from(crypto_key_pair_generates)
@@ -88,14 +205,347 @@ impl WebCryptoRsaKeyMaterial {
                 .map_err(|error| anyhow!("{:?}", error))?,
         );
 
-        Ok(WebCryptoRsaKeyMaterial(public_key, Some(private_key)))
+        Ok(WebCryptoRsaKeyMaterial(public_key, Some(private_key), hash))
+    }
+
+    // The CryptoKeys must have been produced with extractable: true, or
+    // SubtleCrypto will reject the export.
+    pub async fn export_jwk(&self) -> Result<JsValue> {
+        let subtle_crypto = Self::get_subtle_crypto()?;
+
+        let public_jwk = JsFuture::from(
+            subtle_crypto
+                .export_key("jwk", &self.0)
+                .map_err(|error| anyhow!("{:?}", error))?,
+        )
+        .await
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        if let Some(private_key) = &self.1 {
+            let private_jwk = JsFuture::from(
+                subtle_crypto
+                    .export_key("jwk", private_key)
+                    .map_err(|error| anyhow!("{:?}", error))?,
+            )
+            .await
+            .map_err(|error| anyhow!("{:?}", error))?;
+
+            let exported = Object::new();
+
+            Reflect::set(&exported, &JsValue::from("publicKey"), &public_jwk)
+                .map_err(|error| anyhow!("{:?}", error))?;
+            Reflect::set(&exported, &JsValue::from("privateKey"), &private_jwk)
+                .map_err(|error| anyhow!("{:?}", error))?;
+
+            Ok(exported.into())
+        } else {
+            Ok(public_jwk)
+        }
+    }
+
+    pub async fn from_jwk(
+        public: JsValue,
+        private: Option<JsValue>,
+        hash: RsaHash,
+    ) -> Result<Self> {
+        let subtle_crypto = Self::get_subtle_crypto()?;
+
+        let algorithm = Object::new();
+
+        Reflect::set(
+            &algorithm,
+            &JsValue::from("name"),
+            &JsValue::from(RSA_ALGORITHM),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        let hash_object = Object::new();
+
+        Reflect::set(
+            &hash_object,
+            &JsValue::from("name"),
+            &JsValue::from(hash.name()),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        Reflect::set(
+            &algorithm,
+            &JsValue::from("hash"),
+            &JsValue::from(hash_object),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        let public_uses = Array::new();
+        public_uses.push(&JsValue::from("verify"));
+
+        Self::prepare_jwk_for_import(&public, "verify", hash)?;
+
+        let public_key = CryptoKey::from(
+            JsFuture::from(
+                subtle_crypto
+                    .import_key_with_object(
+                        "jwk",
+                        &public.clone().into(),
+                        &algorithm,
+                        true,
+                        &public_uses,
+                    )
+                    .map_err(|error| anyhow!("{:?}", error))?,
+            )
+            .await
+            .map_err(|error| anyhow!("{:?}", error))?,
+        );
+
+        let private_key = match private {
+            Some(private) => {
+                Self::prepare_jwk_for_import(&private, "sign", hash)?;
+
+                let private_uses = Array::new();
+                private_uses.push(&JsValue::from("sign"));
+
+                Some(CryptoKey::from(
+                    JsFuture::from(
+                        subtle_crypto
+                            .import_key_with_object(
+                                "jwk",
+                                &private.into(),
+                                &algorithm,
+                                true,
+                                &private_uses,
+                            )
+                            .map_err(|error| anyhow!("{:?}", error))?,
+                    )
+                    .await
+                    .map_err(|error| anyhow!("{:?}", error))?,
+                ))
+            }
+            None => None,
+        };
+
+        Ok(WebCryptoRsaKeyMaterial(public_key, private_key, hash))
+    }
+
+    fn prepare_jwk_for_import(jwk: &JsValue, key_op: &str, hash: RsaHash) -> Result<()> {
+        let key_ops = Array::new();
+        key_ops.push(&JsValue::from(key_op));
+
+        Reflect::set(jwk, &JsValue::from("key_ops"), &key_ops)
+            .map_err(|error| anyhow!("{:?}", error))?;
+        Reflect::set(
+            jwk,
+            &JsValue::from("alg"),
+            &JsValue::from(hash.jwt_algorithm_name()),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        Ok(())
+    }
+
+    // Not marked extractable: the whole point of wrapping is to keep the
+    // private key encrypted under a KEK that itself never leaves SubtleCrypto.
+    pub async fn generate_wrapping_key() -> Result<CryptoKey> {
+        let subtle_crypto = Self::get_subtle_crypto()?;
+        let algorithm = Object::new();
+
+        Reflect::set(
+            &algorithm,
+            &JsValue::from("name"),
+            &JsValue::from(AES_WRAPPING_ALGORITHM),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+        Reflect::set(&algorithm, &JsValue::from("length"), &JsValue::from(256u16))
+            .map_err(|error| anyhow!("{:?}", error))?;
+
+        let uses = Array::new();
+
+        uses.push(&JsValue::from("wrapKey"));
+        uses.push(&JsValue::from("unwrapKey"));
+
+        let key = JsFuture::from(
+            subtle_crypto
+                .generate_key_with_object(&algorithm, false, &uses)
+                .map_err(|error| anyhow!("{:?}", error))?,
+        )
+        .await
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        Ok(CryptoKey::from(key))
+    }
+
+    // Like generate_wrapping_key, the imported CryptoKey is not extractable.
+    pub async fn import_wrapping_key(raw_key_bytes: &[u8]) -> Result<CryptoKey> {
+        let subtle_crypto = Self::get_subtle_crypto()?;
+        let algorithm = Object::new();
+
+        Reflect::set(
+            &algorithm,
+            &JsValue::from("name"),
+            &JsValue::from(AES_WRAPPING_ALGORITHM),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        let uses = Array::new();
+
+        uses.push(&JsValue::from("wrapKey"));
+        uses.push(&JsValue::from("unwrapKey"));
+
+        let data = unsafe { Uint8Array::view(raw_key_bytes) };
+
+        let key = JsFuture::from(
+            subtle_crypto
+                .import_key_with_object("raw", &data, &algorithm, false, &uses)
+                .map_err(|error| anyhow!("{:?}", error))?,
+        )
+        .await
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        Ok(CryptoKey::from(key))
+    }
+
+    fn generate_iv() -> Result<[u8; AES_GCM_IV_LENGTH]> {
+        let global = js_sys::global();
+        let crypto = match Reflect::get(&global, &JsValue::from("crypto")) {
+            Ok(value) => value.dyn_into::<Crypto>().expect("Unexpected API"),
+            _ => return Err(anyhow!("Could not access WebCrypto API")),
+        };
+
+        let mut iv = [0u8; AES_GCM_IV_LENGTH];
+
+        crypto
+            .get_random_values_with_u8_array(&mut iv)
+            .map_err(|error| anyhow!("{:?}", error))?;
+
+        Ok(iv)
+    }
+
+    // Returns iv ‖ ciphertext; a fresh IV is drawn for every call. AES-GCM is
+    // used instead of AES-KW (RFC 3394) because AES-KW requires its plaintext
+    // to be a multiple of 8 bytes, and a "jwk"-exported RSA private key's JSON
+    // length isn't guaranteed to land on an 8-byte boundary.
+    pub async fn wrap_key(&self, wrapping_key: &CryptoKey) -> Result<Vec<u8>> {
+        let private_key = self.private_key()?;
+        let subtle_crypto = Self::get_subtle_crypto()?;
+
+        let iv = Self::generate_iv()?;
+        let algorithm = Object::new();
+
+        Reflect::set(
+            &algorithm,
+            &JsValue::from("name"),
+            &JsValue::from(AES_WRAPPING_ALGORITHM),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+        Reflect::set(
+            &algorithm,
+            &JsValue::from("iv"),
+            &JsValue::from(unsafe { Uint8Array::view(&iv) }),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        let wrapped = Uint8Array::new(
+            &JsFuture::from(
+                subtle_crypto
+                    .wrap_key_with_object("jwk", private_key, wrapping_key, &algorithm)
+                    .map_err(|error| anyhow!("{:?}", error))?,
+            )
+            .await
+            .map_err(|error| anyhow!("{:?}", error))?
+            .dyn_into::<ArrayBuffer>()
+            .map_err(|error| anyhow!("{:?}", error))?,
+        );
+
+        let mut wrapped_with_iv = Vec::with_capacity(iv.len() + wrapped.length() as usize);
+        wrapped_with_iv.extend_from_slice(&iv);
+        wrapped_with_iv.extend_from_slice(wrapped.to_vec().as_slice());
+
+        Ok(wrapped_with_iv)
+    }
+
+    pub async fn unwrap_to_key_material(
+        wrapped_private_key: &[u8],
+        public_key: CryptoKey,
+        wrapping_key: &CryptoKey,
+        hash: RsaHash,
+    ) -> Result<WebCryptoRsaKeyMaterial> {
+        if wrapped_private_key.len() <= AES_GCM_IV_LENGTH {
+            return Err(anyhow!("Wrapped private key is missing its AES-GCM IV"));
+        }
+
+        let (iv, ciphertext) = wrapped_private_key.split_at(AES_GCM_IV_LENGTH);
+
+        let subtle_crypto = Self::get_subtle_crypto()?;
+
+        let wrap_algorithm = Object::new();
+
+        Reflect::set(
+            &wrap_algorithm,
+            &JsValue::from("name"),
+            &JsValue::from(AES_WRAPPING_ALGORITHM),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+        Reflect::set(
+            &wrap_algorithm,
+            &JsValue::from("iv"),
+            &JsValue::from(unsafe { Uint8Array::view(iv) }),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        let unwrapped_key_algorithm = Object::new();
+
+        Reflect::set(
+            &unwrapped_key_algorithm,
+            &JsValue::from("name"),
+            &JsValue::from(RSA_ALGORITHM),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        let hash_object = Object::new();
+
+        Reflect::set(
+            &hash_object,
+            &JsValue::from("name"),
+            &JsValue::from(hash.name()),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        Reflect::set(
+            &unwrapped_key_algorithm,
+            &JsValue::from("hash"),
+            &JsValue::from(hash_object),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        let uses = Array::new();
+        uses.push(&JsValue::from("sign"));
+
+        let data = unsafe { Uint8Array::view(ciphertext) };
+
+        let private_key = CryptoKey::from(
+            JsFuture::from(
+                subtle_crypto
+                    .unwrap_key_with_buffer_source_and_object_and_object(
+                        "jwk",
+                        &data,
+                        wrapping_key,
+                        &wrap_algorithm,
+                        &unwrapped_key_algorithm,
+                        true,
+                        &uses,
+                    )
+                    .map_err(|error| anyhow!("{:?}", error))?,
+            )
+            .await
+            .map_err(|error| anyhow!("{:?}", error))?,
+        );
+
+        Ok(WebCryptoRsaKeyMaterial(public_key, Some(private_key), hash))
     }
 }
 
 #[async_trait(?Send)]
 impl KeyMaterial for WebCryptoRsaKeyMaterial {
     fn get_jwt_algorithm_name(&self) -> String {
-        RSA_ALGORITHM.into()
+        self.2.jwt_algorithm_name().into()
     }
 
     async fn get_did(&self) -> Result<String> {
@@ -136,7 +586,7 @@ impl KeyMaterial for WebCryptoRsaKeyMaterial {
         Reflect::set(
             &algorithm,
             &JsValue::from("saltLength"),
-            &JsValue::from(128u8),
+            &JsValue::from(self.2.salt_length()),
         )
         .map_err(|error| anyhow!("{:?}", error))?;
 
@@ -169,7 +619,7 @@ impl KeyMaterial for WebCryptoRsaKeyMaterial {
         Reflect::set(
             &algorithm,
             &JsValue::from("saltLength"),
-            &JsValue::from(128u8),
+            &JsValue::from(self.2.salt_length()),
         )
         .map_err(|error| anyhow!("{:?}", error))?;
 
@@ -195,15 +645,209 @@ impl KeyMaterial for WebCryptoRsaKeyMaterial {
     }
 }
 
+pub struct WebCryptoEcdsaKeyMaterial(pub CryptoKey, pub Option<CryptoKey>, pub EcdsaCurve);
+
+impl WebCryptoEcdsaKeyMaterial {
+    fn private_key(&self) -> Result<&CryptoKey> {
+        match &self.1 {
+            Some(key) => Ok(key),
+            None => Err(anyhow!("No private key configured")),
+        }
+    }
+
+    pub async fn generate(curve: EcdsaCurve) -> Result<WebCryptoEcdsaKeyMaterial> {
+        let subtle_crypto = WebCryptoRsaKeyMaterial::get_subtle_crypto()?;
+        let algorithm = Object::new();
+
+        Reflect::set(
+            &algorithm,
+            &JsValue::from("name"),
+            &JsValue::from(ECDSA_ALGORITHM),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        Reflect::set(
+            &algorithm,
+            &JsValue::from("namedCurve"),
+            &JsValue::from(curve.named_curve()),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        let uses = Array::new();
+
+        uses.push(&JsValue::from("sign"));
+        uses.push(&JsValue::from("verify"));
+
+        let crypto_key_pair_generates = subtle_crypto
+            .generate_key_with_object(&algorithm, false, &uses)
+            .map_err(|error| anyhow!("{:?}", error))?;
+        let crypto_key_pair = CryptoKeyPair::from(
+            JsFuture::from(crypto_key_pair_generates)
+                .await
+                .map_err(|error| anyhow!("{:?}", error))?,
+        );
+
+        let public_key = CryptoKey::from(
+            Reflect::get(&crypto_key_pair, &JsValue::from("publicKey"))
+                .map_err(|error| anyhow!("{:?}", error))?,
+        );
+        let private_key = CryptoKey::from(
+            Reflect::get(&crypto_key_pair, &JsValue::from("privateKey"))
+                .map_err(|error| anyhow!("{:?}", error))?,
+        );
+
+        Ok(WebCryptoEcdsaKeyMaterial(
+            public_key,
+            Some(private_key),
+            curve,
+        ))
+    }
+
+    // Compress an uncompressed SEC1 point (0x04 ‖ X ‖ Y) down to 0x02/0x03 ‖ X,
+    // choosing the prefix based on the parity of Y, then prepend the curve's
+    // did:key multicodec prefix.
+    fn compress_and_tag_public_key(&self, raw_point: &[u8]) -> Vec<u8> {
+        let coordinate_size = self.2.coordinate_size();
+        let x = &raw_point[1..1 + coordinate_size];
+        let y = &raw_point[1 + coordinate_size..1 + 2 * coordinate_size];
+        let parity_prefix = if y[y.len() - 1] & 1 == 0 { 0x02 } else { 0x03 };
+
+        let prefix = self.2.multicodec_prefix();
+        let mut tagged = Vec::with_capacity(prefix.len() + 1 + coordinate_size);
+        tagged.extend_from_slice(prefix);
+        tagged.push(parity_prefix);
+        tagged.extend_from_slice(x);
+        tagged
+    }
+}
+
+#[async_trait(?Send)]
+impl KeyMaterial for WebCryptoEcdsaKeyMaterial {
+    fn get_jwt_algorithm_name(&self) -> String {
+        self.2.jwt_algorithm_name().into()
+    }
+
+    async fn get_did(&self) -> Result<String> {
+        let public_key = &self.0;
+        let subtle_crypto = WebCryptoRsaKeyMaterial::get_subtle_crypto()?;
+
+        let raw_point = Uint8Array::new(
+            &JsFuture::from(
+                subtle_crypto
+                    .export_key("raw", public_key)
+                    .expect("Could not access key extraction API"),
+            )
+            .await
+            .expect("Failed to extract public key bytes")
+            .dyn_into::<ArrayBuffer>()
+            .expect("Bytes were not an ArrayBuffer"),
+        )
+        .to_vec();
+
+        let tagged_public_key = self.compress_and_tag_public_key(raw_point.as_slice());
+
+        Ok(format!(
+            "did:key:{}",
+            multibase::encode(multibase::Base::Base58Btc, tagged_public_key)
+        ))
+    }
+
+    async fn sign(&self, payload: &[u8]) -> Result<Vec<u8>> {
+        let key = self.private_key()?;
+        let subtle_crypto = WebCryptoRsaKeyMaterial::get_subtle_crypto()?;
+        let algorithm = Object::new();
+
+        Reflect::set(
+            &algorithm,
+            &JsValue::from("name"),
+            &JsValue::from(ECDSA_ALGORITHM),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        let hash = Object::new();
+
+        Reflect::set(
+            &hash,
+            &JsValue::from("name"),
+            &JsValue::from(self.2.hash_name()),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        Reflect::set(&algorithm, &JsValue::from("hash"), &JsValue::from(hash))
+            .map_err(|error| anyhow!("{:?}", error))?;
+
+        let data = unsafe { Uint8Array::view(payload) };
+
+        // WebCrypto already returns the ECDSA signature as raw r‖s, which is
+        // exactly the encoding the JWT ES256/384/512 wire format expects.
+        let result = Uint8Array::new(
+            &JsFuture::from(
+                subtle_crypto
+                    .sign_with_object_and_buffer_source(&algorithm, key, &data)
+                    .map_err(|error| anyhow!("{:?}", error))?,
+            )
+            .await
+            .map_err(|error| anyhow!("{:?}", error))?,
+        );
+
+        Ok(result.to_vec())
+    }
+
+    async fn verify(&self, payload: &[u8], signature: &[u8]) -> Result<()> {
+        let key = &self.0;
+        let subtle_crypto = WebCryptoRsaKeyMaterial::get_subtle_crypto()?;
+        let algorithm = Object::new();
+
+        Reflect::set(
+            &algorithm,
+            &JsValue::from("name"),
+            &JsValue::from(ECDSA_ALGORITHM),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        let hash = Object::new();
+
+        Reflect::set(
+            &hash,
+            &JsValue::from("name"),
+            &JsValue::from(self.2.hash_name()),
+        )
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        Reflect::set(&algorithm, &JsValue::from("hash"), &JsValue::from(hash))
+            .map_err(|error| anyhow!("{:?}", error))?;
+
+        let signature = unsafe { Uint8Array::view(signature.as_ref()) };
+        let data = unsafe { Uint8Array::view(payload.as_ref()) };
+
+        let valid = JsFuture::from(
+            subtle_crypto
+                .verify_with_object_and_buffer_source_and_buffer_source(
+                    &algorithm, &key, &signature, &data,
+                )
+                .map_err(|error| anyhow!("{:?}", error))?,
+        )
+        .await
+        .map_err(|error| anyhow!("{:?}", error))?
+        .dyn_into::<Boolean>()
+        .map_err(|error| anyhow!("{:?}", error))?;
+
+        match valid.is_truthy() {
+            true => Ok(()),
+            false => Err(anyhow!("Could not verify signature")),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use wasm_bindgen_test::*;
 
     wasm_bindgen_test_configure!(run_in_browser);
 
-    use super::WebCryptoRsaKeyMaterial;
-    use ucan::crypto::KeyMaterial;
+    use super::{EcdsaCurve, RsaHash, WebCryptoEcdsaKeyMaterial, WebCryptoRsaKeyMaterial};
     use ucan::builder::UcanBuilder;
+    use ucan::crypto::KeyMaterial;
 
     #[wasm_bindgen_test]
     async fn it_can_sign_and_verify_data() {
@@ -234,4 +878,98 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[wasm_bindgen_test]
+    async fn it_can_round_trip_through_a_jwk() {
+        let key_material =
+            WebCryptoRsaKeyMaterial::generate_extractable(None, RsaHash::Sha256, true)
+                .await
+                .unwrap();
+        let jwk = key_material.export_jwk().await.unwrap();
+
+        let public_jwk =
+            js_sys::Reflect::get(&jwk, &wasm_bindgen::JsValue::from("publicKey")).unwrap();
+        let private_jwk =
+            js_sys::Reflect::get(&jwk, &wasm_bindgen::JsValue::from("privateKey")).unwrap();
+
+        let restored_key_material =
+            WebCryptoRsaKeyMaterial::from_jwk(public_jwk, Some(private_jwk), RsaHash::Sha256)
+                .await
+                .unwrap();
+
+        let data = &[0xdeu8, 0xad, 0xbe, 0xef];
+        let signature = restored_key_material.sign(data).await.unwrap();
+
+        restored_key_material
+            .verify(data, signature.as_ref())
+            .await
+            .unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    async fn it_can_sign_and_verify_data_with_each_pss_hash() {
+        for hash in [RsaHash::Sha256, RsaHash::Sha384, RsaHash::Sha512] {
+            let key_material = WebCryptoRsaKeyMaterial::generate_extractable(None, hash, false)
+                .await
+                .unwrap();
+            assert_eq!(
+                key_material.get_jwt_algorithm_name(),
+                hash.jwt_algorithm_name()
+            );
+
+            let data = &[0xdeu8, 0xad, 0xbe, 0xef];
+            let signature = key_material.sign(data).await.unwrap();
+
+            key_material.verify(data, signature.as_ref()).await.unwrap();
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn it_can_wrap_and_unwrap_a_private_key() {
+        let key_material =
+            WebCryptoRsaKeyMaterial::generate_extractable(None, RsaHash::Sha256, true)
+                .await
+                .unwrap();
+        let wrapping_key = WebCryptoRsaKeyMaterial::generate_wrapping_key()
+            .await
+            .unwrap();
+
+        let wrapped_private_key = key_material.wrap_key(&wrapping_key).await.unwrap();
+
+        let restored_key_material = WebCryptoRsaKeyMaterial::unwrap_to_key_material(
+            wrapped_private_key.as_slice(),
+            key_material.0.clone(),
+            &wrapping_key,
+            RsaHash::Sha256,
+        )
+        .await
+        .unwrap();
+
+        let data = &[0xdeu8, 0xad, 0xbe, 0xef];
+        let signature = restored_key_material.sign(data).await.unwrap();
+
+        restored_key_material
+            .verify(data, signature.as_ref())
+            .await
+            .unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    async fn it_can_sign_and_verify_data_with_ecdsa() {
+        let key_material = WebCryptoEcdsaKeyMaterial::generate(EcdsaCurve::P256)
+            .await
+            .unwrap();
+        let data = &[0xdeu8, 0xad, 0xbe, 0xef];
+        let signature = key_material.sign(data).await.unwrap();
+
+        key_material.verify(data, signature.as_ref()).await.unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    async fn it_can_produce_a_did_for_each_ecdsa_curve() {
+        for curve in [EcdsaCurve::P256, EcdsaCurve::P384, EcdsaCurve::P521] {
+            let key_material = WebCryptoEcdsaKeyMaterial::generate(curve).await.unwrap();
+            key_material.get_did().await.unwrap();
+        }
+    }
 }